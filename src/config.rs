@@ -0,0 +1,39 @@
+use {
+	std::{fs, path::PathBuf},
+	serde::Deserialize,
+	directories::ProjectDirs,
+	anyhow::{Context, Result},
+};
+
+/// Persisted defaults loaded from `g3k.toml` in the platform config directory. Every
+/// field is optional; a missing file or key simply falls through to the built-in default,
+/// and any value here is in turn overridden by the matching CLI flag.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct Config {
+	pub threads: Option<usize>,
+	pub uid: Option<String>,
+	pub max_backflow: Option<usize>,
+	pub save_path: Option<String>,
+	/// default match patterns, used when none are given on the command line
+	pub patterns: Option<Vec<String>>,
+}
+
+impl Config {
+	/// Load `g3k.toml` from the platform config directory. Returns defaults when the file
+	/// is absent; a present-but-malformed file is a hard error rather than a silent reset.
+	pub fn load() -> Result<Self> {
+		match Self::path() {
+			Some(path) if path.exists() => {
+				let text = fs::read_to_string(&path)
+					.with_context(|| format!("reading config {}", path.display()))?;
+				toml::from_str(&text)
+					.with_context(|| format!("parsing config {}", path.display()))
+			},
+			_ => Ok(Self::default()),
+		}
+	}
+
+	fn path() -> Option<PathBuf> {
+		ProjectDirs::from("", "", "g3k").map(|dirs| dirs.config_dir().join("g3k.toml"))
+	}
+}
@@ -1,11 +1,18 @@
 use {
-	std::thread::spawn,
+	std::{
+		io::IsTerminal,
+		sync::{Arc, atomic::{AtomicBool, AtomicU64, Ordering}},
+		thread::{sleep, spawn, JoinHandle},
+		time::Duration as StdDuration,
+	},
 	pgp::{
 		composed::{
-			KeyType,
-			key::{SecretKey, SecretKeyParamsBuilder},
+			KeyType, SignedSecretKey,
+			key::{SecretKey, SecretKeyParams, SecretKeyParamsBuilder, SubkeyParamsBuilder},
 		},
-		types::KeyTrait,
+		packet::{PacketTrait, PublicKey as PublicKeyPacket, Subpacket, SubpacketData},
+		ser::Serialize,
+		types::{KeyTrait, KeyVersion},
 	},
 	chrono::{
 		DateTime, Duration,
@@ -13,126 +20,470 @@ use {
 	},
 	argh::FromArgs,
 	hex::ToHex,
+	sha1::{Digest, Sha1},
 	num_cpus::get_physical,
-	anyhow::{Context, Result},
+	rand::{RngCore, SeedableRng, rngs::OsRng},
+	rand_chacha::ChaCha20Rng,
+	anyhow::{ensure, Context, Result},
 };
 
+mod config;
+mod matcher;
+use {config::Config, matcher::Matcher};
+
+/// Built-in default user ID when none is given on the command line or in the config file.
+const DEFAULT_UID: &str = "G3K";
+/// Built-in default backflow window, in seconds (30 days equivalent).
+const DEFAULT_MAX_BACKFLOW: usize = 86400 * 30;
+/// Built-in default validity period when `--expires` is not given, in seconds (3 years),
+/// matching sequoia's convention of a few years rather than never-expiring.
+const DEFAULT_EXPIRATION: StdDuration = StdDuration::from_secs(86400 * 365 * 3);
+
 #[derive(Debug, Clone)]
 #[derive(FromArgs)]
 /// Generate Good-looking GPG Keys
 struct CliArgs {
 	/// number of threads to use, defaults to number of physical CPU cores
-	#[argh(option, default = "get_physical()")]
-	threads: usize,
+	#[argh(option)]
+	threads: Option<usize>,
 	/// max backflow of one iteration, in seconds, defaults to 30 days equivalent
-	#[argh(option, default = "86400 * 30")]
-	max_backflow: usize,
+	#[argh(option)]
+	max_backflow: Option<usize>,
+	/// max forward flow past the anchor, in seconds; defaults to 0 (backward only)
+	#[argh(option)]
+	max_forward: Option<usize>,
+	/// base creation time as an ISO-8601 / RFC-3339 timestamp; defaults to now
+	#[argh(option)]
+	created_at: Option<String>,
 	/// file name to save key to, defaults to FINGERPRINT.key in working directory
-	#[argh(option, default = "String::new()")]
-	save_path: String,
+	#[argh(option)]
+	save_path: Option<String>,
 	/// don't save, output armored key certifitace to stdout; off by default
 	#[argh(switch)]
 	no_save: bool,
+	/// match hex patterns case-sensitively; off by default (fingerprints are lowercase)
+	#[argh(switch)]
+	case_sensitive: bool,
+	/// don't attach an encryption subkey; the resulting cert can only certify/sign
+	#[argh(switch)]
+	no_subkey: bool,
+	/// validity period as a duration (e.g. "2y", "90d") or "never"; defaults to 3 years
+	#[argh(option)]
+	expires: Option<String>,
+	/// passphrase to encrypt the exported secret key; prompts interactively if omitted
+	#[argh(option)]
+	passphrase: Option<String>,
+	/// write an unencrypted secret key without prompting (explicit opt-out)
+	#[argh(switch)]
+	no_passphrase: bool,
+	/// store the passphrase in the OS keyring, keyed by the resulting fingerprint
+	#[argh(switch)]
+	keyring: bool,
 	/// user ID
-	#[argh(option, default = "String::from(\"G3K\")")]
-	uid: String,
-	/// desired fingerprint / Key ID suffix
+	#[argh(option)]
+	uid: Option<String>,
+	/// desired patterns as `mode:value` (prefix/suffix/any/keyid); a bare value means suffix
 	#[argh(positional)]
-	suffix: String,
+	patterns: Vec<String>,
+}
+
+/// Effective run settings after layering CLI flags over the config file over built-ins.
+#[derive(Clone)]
+struct Settings {
+	threads: usize,
+	max_backflow: usize,
+	max_forward: usize,
+	anchor: DateTime<Utc>,
+	save_path: String,
+	no_save: bool,
+	uid: String,
+	subkey: bool,
+	expiration: Option<StdDuration>,
+	/// passphrase the exported key is encrypted with, or `None` for an unencrypted key
+	passphrase: Option<String>,
+	keyring: bool,
+	matcher: Matcher,
+}
+
+impl Settings {
+	fn resolve(args: CliArgs, config: Config) -> Result<Self> {
+		let patterns = if args.patterns.is_empty() {
+			config.patterns.clone().context("no patterns given on the command line or in g3k.toml")?
+		} else {
+			args.patterns
+		};
+		let expiration = match args.expires {
+			Some(spec) => parse_expiration(&spec)?,
+			None => Some(DEFAULT_EXPIRATION),
+		};
+		let anchor = match args.created_at {
+			Some(spec) => DateTime::parse_from_rfc3339(&spec)
+				.with_context(|| format!("invalid --created-at \"{spec}\""))?
+				.with_timezone(&Utc),
+			None => Utc::now(),
+		};
+		Ok(Self {
+			threads: args.threads.or(config.threads).unwrap_or_else(get_physical),
+			max_backflow: args.max_backflow.or(config.max_backflow).unwrap_or(DEFAULT_MAX_BACKFLOW),
+			max_forward: args.max_forward.unwrap_or(0),
+			anchor,
+			save_path: args.save_path.or(config.save_path).unwrap_or_default(),
+			no_save: args.no_save,
+			uid: args.uid.or(config.uid).unwrap_or_else(|| DEFAULT_UID.into()),
+			subkey: !args.no_subkey,
+			expiration,
+			passphrase: resolve_passphrase(args.passphrase, args.no_passphrase)?,
+			keyring: args.keyring,
+			matcher: Matcher::new(patterns, args.case_sensitive)?,
+		})
+	}
+}
+
+/// Spawn a background thread that, once a second, reports candidates tried per second, the
+/// running total, and the expected number of attempts for the chosen pattern length.
+fn spawn_reporter(tried: Arc<AtomicU64>, expected: f64, done: Arc<AtomicBool>) -> JoinHandle<()> {
+	spawn(move || {
+		let mut last = 0u64;
+		while !done.load(Ordering::Relaxed) {
+			sleep(StdDuration::from_secs(1));
+			let total = tried.load(Ordering::Relaxed);
+			eprint!("\r{} c/s, {total} tried, ~{expected:.0} expected   ", total - last);
+			last = total;
+		}
+	})
+}
+
+/// Settle on the passphrase to encrypt the exported key with. A bare key is only written
+/// when explicitly requested (`--no-passphrase`); an empty passphrase at the interactive
+/// prompt is rejected, and unattended runs with neither flag are an error rather than a
+/// silent unencrypted write.
+fn resolve_passphrase(explicit: Option<String>, no_passphrase: bool) -> Result<Option<String>> {
+	if no_passphrase {
+		// The two flags pull in opposite directions; silently honouring one and dropping the
+		// other would write a key the caller did not ask for, so reject the combination.
+		ensure!(explicit.is_none(), "--passphrase and --no-passphrase are mutually exclusive");
+		return Ok(None);
+	}
+	if let Some(passphrase) = explicit {
+		return Ok(Some(passphrase));
+	}
+	ensure!(std::io::stdin().is_terminal(),
+		"no passphrase given; pass --passphrase or --no-passphrase for unattended use");
+	let passphrase = rpassword::prompt_password("Passphrase for the new secret key: ")?;
+	let confirm = rpassword::prompt_password("Confirm passphrase: ")?;
+	ensure!(passphrase == confirm, "passphrases did not match");
+	ensure!(!passphrase.is_empty(), "empty passphrase; use --no-passphrase for an unencrypted key");
+	Ok(Some(passphrase))
+}
+
+/// Parse an expiration spec: `never` for no expiry, or a count with a `y`/`w`/`d`/`h`/`s`
+/// suffix (a bare number is seconds).
+fn parse_expiration(spec: &str) -> Result<Option<StdDuration>> {
+	if spec.eq_ignore_ascii_case("never") {
+		return Ok(None);
+	}
+	let (value, unit_secs) = match spec.as_bytes().last() {
+		Some(b'y') => (&spec[..spec.len() - 1], 86400 * 365),
+		Some(b'w') => (&spec[..spec.len() - 1], 86400 * 7),
+		Some(b'd') => (&spec[..spec.len() - 1], 86400),
+		Some(b'h') => (&spec[..spec.len() - 1], 3600),
+		Some(b's') => (&spec[..spec.len() - 1], 1),
+		_ => (spec, 1),
+	};
+	let count: u64 = value.parse().with_context(|| format!("invalid duration \"{spec}\""))?;
+	let secs = count.checked_mul(unit_secs).with_context(|| format!("duration \"{spec}\" overflows"))?;
+	// The v4 key-expiration-time subpacket is a 32-bit second offset, so anything larger
+	// would be silently truncated when written; reject it up front instead.
+	ensure!(secs <= u32::MAX as u64, "duration \"{spec}\" exceeds the {}-second maximum", u32::MAX);
+	Ok(Some(StdDuration::from_secs(secs)))
 }
 
+/// Length in bytes of the creation time field in a v4 public-key packet.
+const CREATED_AT_LEN: usize = 4;
+
 #[derive(Clone)]
 struct Builder {
-	kb: SecretKeyParamsBuilder,
+	uid: String,
 	ct: DateTime<Utc>,
-	k: Option<SecretKey>,
+	/// attach a Cv25519 encryption subkey bound at the settled creation time
+	subkey: bool,
+	/// validity period of the primary key and subkey, or `None` to never expire
+	expiration: Option<StdDuration>,
+	/// passphrase the secret material is encrypted under, or `None` for an unencrypted key
+	passphrase: Option<String>,
+	/// RNG seed that pins the primary key material. The search generates material once from
+	/// this seed; `materialize` re-derives the identical primary by reseeding, so the
+	/// settled creation time can be baked in through the supported builder path.
+	seed: [u8; 32],
+	/// OpenPGP v4 fingerprint preimage: `0x99`, 16-bit body length, then the public-key
+	/// packet body (`0x04`, the 4-byte big-endian creation time at `hashed[4..8]`,
+	/// algorithm, key material). Built once per key; the flow loop only rewrites the
+	/// creation-time bytes so each candidate second is a bare SHA-1.
+	hashed: Vec<u8>,
 }
 
 impl Builder {
-	/// Create a builder. Hard coded to Cv25519, may provide options later.
-	fn new (uid: &str) -> Self {
-		let mut pgp_builder = SecretKeyParamsBuilder::default();
-		pgp_builder
+	/// Create a builder. The EdDSA primary key certifies and signs; an optional Cv25519
+	/// subkey adds encryption so the cert is usable as a normal key.
+	fn new (uid: &str, anchor: DateTime<Utc>, subkey: bool, expiration: Option<StdDuration>, passphrase: Option<String>) -> Self {
+		Self {
+			uid: uid.to_string(),
+			ct: anchor,
+			subkey,
+			expiration,
+			passphrase,
+			seed: [0; 32],
+			hashed: Vec::new(),
+		}
+	}
+
+	/// Assemble the key parameters at creation time `ct`. Driven from a seeded RNG this is
+	/// deterministic, so the same seed always yields the same primary key material.
+	fn params(&self, ct: DateTime<Utc>) -> Result<SecretKeyParams> {
+		let mut builder = SecretKeyParamsBuilder::default();
+		builder
 			.key_type(KeyType::EdDSA)
 			.can_create_certificates(true)
 			.can_sign(true)
-			.primary_user_id(uid.into());
-		Self {
-			kb: pgp_builder,
-			ct: Utc::now(),
-			k: None,
+			.primary_user_id(self.uid.clone())
+			.created_at(ct);
+		// The validity period is applied after signing (see `bind_expiration`): this rpgp
+		// version only records `expiration` as a v3-style packet field and never emits the
+		// v4 key-expiration-time self-signature subpacket gpg reads, so setting it here would
+		// be a silent no-op.
+		if let Some(pw) = &self.passphrase {
+			builder.passphrase(Some(pw.clone()));
+		}
+		if self.subkey {
+			let mut subkey = SubkeyParamsBuilder::default();
+			subkey
+				.key_type(KeyType::ECDH)
+				.can_encrypt(true)
+				.created_at(ct);
+			builder.subkey(subkey.build().map_err(|e| anyhow::anyhow!(e))?);
 		}
+		builder.build().map_err(|e| anyhow::anyhow!(e))
 	}
 
+	/// Generate primary key material once, then cache its fingerprint preimage. This is the
+	/// only step that runs a curve keygen; every candidate second reuses this buffer.
 	fn gen(&mut self) -> Result<()> {
-		self.kb.created_at(self.ct);
-		match self.kb.build() {
-			Ok(sk) => {
-				self.k = Some(sk.generate()?);
-				Ok(())
-			},
-			Err(e) => anyhow::bail!(e)
-		}
+		OsRng.fill_bytes(&mut self.seed);
+		// Only the primary key decides the fingerprint, and it is the first draw from the
+		// RNG, so deriving just its public params here matches what `materialize` rebuilds.
+		let mut rng = ChaCha20Rng::from_seed(self.seed);
+		let (public_params, _) = KeyType::EdDSA.generate_with_rng(&mut rng, None)?;
+		let primary = PublicKeyPacket::new(
+			Default::default(),
+			KeyVersion::V4,
+			KeyType::EdDSA.to_alg(),
+			self.ct,
+			None,
+			public_params,
+		)?;
+		self.hashed = fingerprint_preimage(&primary.to_bytes()?);
+		Ok(())
+	}
+
+	/// Point the candidate at a specific creation time, rewriting the cached preimage in
+	/// place. This is far faster than generating a whole new key.
+	fn set_created_at(&mut self, ct: DateTime<Utc>) {
+		self.ct = ct;
+		let secs = (ct.timestamp() as u32).to_be_bytes();
+		self.hashed[4..4 + CREATED_AT_LEN].copy_from_slice(&secs);
+	}
+
+	/// Fingerprint of the current candidate second — a ~50-byte SHA-1, no keygen.
+	fn fingerprint(&self) -> String {
+		Sha1::digest(&self.hashed).encode_hex::<String>()
 	}
 
-	/// "Flow" back creation time for one second. This is faster than generating a whole new key.
-	fn backflow(&mut self) {
-		self.ct = self.ct - Duration::seconds(1);
+	/// Materialize the real secret key at the settled creation time. Reseeding reproduces
+	/// the identical primary key material, and generating through the builder gives the cert
+	/// its key flags and subkey; the expiration is bound separately in `bind_expiration`.
+	fn materialize(&self) -> Result<SecretKey> {
+		let mut rng = ChaCha20Rng::from_seed(self.seed);
+		self.params(self.ct)?.generate_with_rng(&mut rng).context("generating key failed")
 	}
 
-	fn fingerprint(&self) -> Result<String> {
-		match &self.k {
-			Some(key) => Ok(key.fingerprint().encode_hex::<String>()),
-			None => anyhow::bail!("no key generated yet"),
+	/// Rewrite the self-signatures so they carry the requested validity period. The builder's
+	/// `expiration` is dropped by this rpgp version, so we append a `KeyExpirationTime`
+	/// subpacket to the primary user-id certification and every subkey binding and re-sign
+	/// them in place. The subpacket stores the lifetime in seconds as a big-endian `u32`, which
+	/// rpgp models as a `DateTime` whose timestamp is that offset.
+	fn bind_expiration(&self, signed: &mut SignedSecretKey) -> Result<()> {
+		let Some(exp) = self.expiration else { return Ok(()) };
+		let offset = DateTime::from_timestamp(exp.as_secs() as i64, 0)
+			.with_context(|| format!("expiration {} s out of range", exp.as_secs()))?;
+		let subpacket = Subpacket::regular(SubpacketData::KeyExpirationTime(offset));
+		let primary = signed.primary_key.clone();
+		for user in &mut signed.details.users {
+			let id = user.id.clone();
+			for sig in &mut user.signatures {
+				let mut config = sig.config.clone();
+				config.hashed_subpackets.retain(|s| !matches!(s.data, SubpacketData::KeyExpirationTime(_)));
+				config.hashed_subpackets.push(subpacket.clone());
+				let pw = self.passphrase.clone().unwrap_or_default();
+				*sig = config.sign_certificate(&primary, move || pw, id.tag(), &id)?;
+			}
+		}
+		for subkey in &mut signed.secret_subkeys {
+			let key = subkey.key.clone();
+			for sig in &mut subkey.signatures {
+				let mut config = sig.config.clone();
+				config.hashed_subpackets.retain(|s| !matches!(s.data, SubpacketData::KeyExpirationTime(_)));
+				config.hashed_subpackets.push(subpacket.clone());
+				let pw = self.passphrase.clone().unwrap_or_default();
+				*sig = config.sign_key_binding(&primary, move || pw, &key)?;
+			}
 		}
+		Ok(())
 	}
 
 	fn armored(&self) -> Result<String> {
-		self.k.as_ref().unwrap().clone()
-			.sign(String::new)?
-			.to_armored_string(None).context("armoring failed")
+		let passphrase = self.passphrase.clone().unwrap_or_default();
+		let mut signed = self.materialize()?.sign(move || passphrase.clone())?;
+		self.bind_expiration(&mut signed)?;
+		// The whole point is that the exported key has the fingerprint we searched for:
+		// bail loudly rather than ship a key whose real fingerprint drifted.
+		let actual = signed.fingerprint().encode_hex::<String>();
+		let expected = self.fingerprint();
+		ensure!(actual == expected,
+			"materialized key fingerprint {actual} does not match searched {expected}");
+		// Guard the rest of the promised cert shape, so a regenerate path that silently drops
+		// the subkey or expiration fails here instead of shipping a key that misleads.
+		ensure!(!self.subkey || !signed.secret_subkeys.is_empty(),
+			"encryption subkey missing from materialized cert");
+		if let Some(exp) = self.expiration {
+			let bound = signed.details.key_expiration_time();
+			ensure!(bound == Some(Duration::seconds(exp.as_secs() as i64)),
+				"expiration not bound into materialized cert (got {bound:?})");
+		}
+		// Re-signing rebuilt the self-signatures; verify they still validate against the key.
+		signed.verify().context("materialized cert failed self-verification")?;
+		signed.to_armored_string(None).context("armoring failed")
 	}
 }
 
+/// Wrap a v4 public-key packet body as the SHA-1 fingerprint preimage: `0x99`, the 16-bit
+/// body length, then the body itself (whose creation time then sits at `buf[4..8]`).
+fn fingerprint_preimage(body: &[u8]) -> Vec<u8> {
+	let mut buf = Vec::with_capacity(3 + body.len());
+	buf.push(0x99);
+	buf.extend_from_slice(&(body.len() as u16).to_be_bytes());
+	buf.extend_from_slice(body);
+	buf
+}
+
 fn main() -> Result<()> {
 	let args: CliArgs = argh::from_env();
-	println!("Looking for suffix \"{}\" with uid \"{}\", {} threads, max backflow {} seconds{}",
-		&args.suffix, &args.uid, &args.threads, &args.max_backflow, if args.no_save { ", no save" } else { "" });
-	let suffix = args.suffix.to_lowercase();
+	let settings = Settings::resolve(args, Config::load()?)?;
+	println!("Looking for {} with uid \"{}\", {} threads, max backflow {} seconds{}",
+		&settings.matcher, &settings.uid, &settings.threads, &settings.max_backflow, if settings.no_save { ", no save" } else { "" });
 	let (sender, receiver) = crossbeam_channel::unbounded();
-	for _ in 0..args.threads {
-		let args = args.clone();
+	let tried = Arc::new(AtomicU64::new(0));
+	let done = Arc::new(AtomicBool::new(false));
+	let reporter = spawn_reporter(tried.clone(), settings.matcher.expected_attempts(), done.clone());
+	for _ in 0..settings.threads {
+		let args = settings.clone();
 		let sender = sender.clone();
-		let suffix = suffix.clone();
+		let tried = tried.clone();
 		spawn(move || -> Result<()> {
 			let mut iterations: usize = 0;
+			let start = args.anchor - Duration::seconds(args.max_backflow as i64);
+			let end = args.anchor + Duration::seconds(args.max_forward as i64);
 			loop {
-				let mut builder = Builder::new(&args.uid);
-				let mut backflow: usize = 0;
-				while backflow < args.max_backflow {
-					builder.backflow();
-					builder.gen()?;
-					let fp = builder.fingerprint()?;
-					if fp.ends_with(&suffix) {
-						sender.send((builder.clone(), iterations))?;
+				let mut builder = Builder::new(&args.uid, args.anchor, args.subkey, args.expiration, args.passphrase.clone());
+				builder.gen()?;
+				let mut ct = start;
+				while ct <= end {
+					builder.set_created_at(ct);
+					tried.fetch_add(1, Ordering::Relaxed);
+					if let Some(pattern) = args.matcher.matches(&builder.fingerprint()) {
+						sender.send((builder.clone(), iterations, pattern.to_string()))?;
 					}
-					backflow += 1;
+					ct += Duration::seconds(1);
 					iterations += 1;
 				}
 			}
 		});
 	}
-	let (result, iterations): (Builder, usize) = receiver.recv()?;
-	println!("Found one after approx. {} iterations", iterations * args.threads);
-	println!("Fingerprint: {}", result.fingerprint()?);
-	if args.no_save {
+	let (result, iterations, pattern): (Builder, usize, String) = receiver.recv()?;
+	// Stop the reporter so its once-a-second line can't overwrite the output below.
+	done.store(true, Ordering::Relaxed);
+	let _ = reporter.join();
+	eprintln!();
+	println!("Found one after approx. {} iterations (matched {})", iterations * settings.threads, pattern);
+	println!("Fingerprint: {}", result.fingerprint());
+	if settings.no_save {
 		println!("Private key:\n\n{}\n", result.armored()?);
 	} else {
-		let save_path = if args.save_path.is_empty() {
-			format!("{}.key", result.fingerprint()?)
-		} else { args.save_path };
+		let save_path = if settings.save_path.is_empty() {
+			format!("{}.key", result.fingerprint())
+		} else { settings.save_path };
 		std::fs::write(&save_path, result.armored()?)?;
 		println!("Written to {}", &save_path);
 	}
+	if settings.keyring {
+		store_passphrase(&result.fingerprint(), settings.passphrase.as_deref())?;
+	}
 	Ok(())
 }
+
+/// Persist the encryption passphrase in the OS keyring, keyed by the resulting
+/// fingerprint, so a later run can unlock the key without reprompting.
+fn store_passphrase(fingerprint: &str, passphrase: Option<&str>) -> Result<()> {
+	let Some(passphrase) = passphrase else {
+		println!("Skipping keyring: key was written without a passphrase");
+		return Ok(());
+	};
+	keyring::Entry::new("g3k", fingerprint)?
+		.set_password(passphrase)
+		.context("storing passphrase in keyring")?;
+	println!("Passphrase stored in keyring for {}", fingerprint);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pgp::composed::Deserializable;
+
+	#[test]
+	fn parse_expiration_units() {
+		let cases = [
+			("never", None),
+			("2y", Some(86400 * 365 * 2)),
+			("3w", Some(86400 * 7 * 3)),
+			("90d", Some(86400 * 90)),
+			("12h", Some(3600 * 12)),
+			("30s", Some(30)),
+			("3600", Some(3600)),
+		];
+		for (spec, secs) in cases {
+			assert_eq!(parse_expiration(spec).unwrap(), secs.map(StdDuration::from_secs), "{spec}");
+		}
+		assert!(parse_expiration("nope").is_err());
+	}
+
+	#[test]
+	fn materialized_cert_carries_expiration_and_subkey() {
+		let exp = StdDuration::from_secs(86400 * 365 * 2);
+		let mut builder = Builder::new("G3K test <test@example.com>", Utc::now(), true, Some(exp), None);
+		builder.gen().unwrap();
+		let armored = builder.armored().unwrap();
+		let (key, _) = SignedSecretKey::from_string(&armored).unwrap();
+		key.verify().unwrap();
+		assert!(!key.secret_subkeys.is_empty(), "encryption subkey missing");
+		assert_eq!(key.details.key_expiration_time(), Some(Duration::seconds(exp.as_secs() as i64)));
+	}
+
+	#[test]
+	fn never_leaves_cert_without_expiration() {
+		let mut builder = Builder::new("G3K test <test@example.com>", Utc::now(), false, None, None);
+		builder.gen().unwrap();
+		let armored = builder.armored().unwrap();
+		let (key, _) = SignedSecretKey::from_string(&armored).unwrap();
+		assert_eq!(key.details.key_expiration_time(), None);
+	}
+}
@@ -0,0 +1,133 @@
+use {
+	std::{fmt, str::FromStr},
+	anyhow::{bail, ensure, Error, Result},
+};
+
+/// Where in the 40-hex fingerprint a pattern must land.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+	/// at the start of the fingerprint
+	Prefix,
+	/// at the end of the fingerprint (the historical default)
+	Suffix,
+	/// anywhere within the fingerprint
+	Anywhere,
+	/// within the last-16 Key ID specifically
+	KeyId,
+}
+
+/// A single desired hex pattern together with where it must appear. Parsed from a
+/// `mode:value` string (`prefix:dead`, `any:cafe`, `keyid:0123abcd`); a bare value with no
+/// `mode:` prefix defaults to suffix matching, preserving the original behaviour.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+	position: Position,
+	needle: String,
+}
+
+impl Pattern {
+	fn matches(&self, fingerprint: &str) -> bool {
+		match self.position {
+			Position::Prefix => fingerprint.starts_with(&self.needle),
+			Position::Suffix => fingerprint.ends_with(&self.needle),
+			Position::Anywhere => fingerprint.contains(&self.needle),
+			Position::KeyId => {
+				let key_id = &fingerprint[fingerprint.len().saturating_sub(16)..];
+				key_id.contains(&self.needle)
+			},
+		}
+	}
+
+	fn normalize(&mut self) {
+		self.needle = self.needle.to_lowercase();
+	}
+}
+
+impl FromStr for Pattern {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let (position, value) = match s.split_once(':') {
+			Some(("prefix", v)) => (Position::Prefix, v),
+			Some(("suffix", v)) => (Position::Suffix, v),
+			Some(("any", v)) => (Position::Anywhere, v),
+			Some(("keyid", v)) => (Position::KeyId, v),
+			Some((mode, _)) => bail!("unknown match mode \"{mode}\", expected prefix/suffix/any/keyid"),
+			None => (Position::Suffix, s),
+		};
+		ensure!(!value.is_empty(), "empty match pattern");
+		ensure!(value.chars().all(|c| c.is_ascii_hexdigit()), "pattern \"{value}\" is not hex");
+		let limit = if position == Position::KeyId { 16 } else { 40 };
+		ensure!(value.len() <= limit, "pattern \"{value}\" is longer than {limit} hex digits");
+		Ok(Self { position, needle: value.to_string() })
+	}
+}
+
+impl fmt::Display for Pattern {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mode = match self.position {
+			Position::Prefix => "prefix",
+			Position::Suffix => "suffix",
+			Position::Anywhere => "any",
+			Position::KeyId => "keyid",
+		};
+		write!(f, "{mode}:{}", self.needle)
+	}
+}
+
+/// A set of alternative patterns; a fingerprint matches when any one of them hits.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+	patterns: Vec<Pattern>,
+}
+
+impl Matcher {
+	/// Build a matcher from raw `mode:value` strings. Unless `case_sensitive`, patterns are
+	/// lowercased to align with the lowercase hex fingerprints we emit.
+	pub fn new<I, S>(patterns: I, case_sensitive: bool) -> Result<Self>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		let mut patterns: Vec<Pattern> = patterns
+			.into_iter()
+			.map(|p| p.as_ref().parse())
+			.collect::<Result<_>>()?;
+		ensure!(!patterns.is_empty(), "at least one match pattern is required");
+		if case_sensitive {
+			// Fingerprints are emitted as lowercase hex, so an uppercase needle could never
+			// match; reject it rather than silently spinning forever.
+			for pattern in &patterns {
+				ensure!(!pattern.needle.chars().any(|c| c.is_ascii_uppercase()),
+					"pattern \"{}\" has uppercase hex but fingerprints are lowercase; drop --case-sensitive or lowercase it",
+					pattern.needle);
+			}
+		} else {
+			for pattern in &mut patterns {
+				pattern.normalize();
+			}
+		}
+		Ok(Self { patterns })
+	}
+
+	/// Rough expected number of attempts to hit any pattern: `16^n` for the shortest
+	/// `n`-hex-digit needle, mirroring the odds of a random fingerprint landing on it.
+	pub fn expected_attempts(&self) -> f64 {
+		let shortest = self.patterns.iter().map(|p| p.needle.len()).min().unwrap_or(0);
+		16f64.powi(shortest as i32)
+	}
+
+	/// Return the first pattern that hits `fingerprint`, or `None`. The fingerprint is
+	/// already lowercase hex and needles were lowercased in `new` unless case-sensitive, so
+	/// the comparison is done directly — no per-candidate allocation in this hot loop.
+	pub fn matches<'a>(&'a self, fingerprint: &str) -> Option<&'a Pattern> {
+		self.patterns.iter().find(|p| p.matches(fingerprint))
+	}
+}
+
+impl fmt::Display for Matcher {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let joined = self.patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" or ");
+		write!(f, "{joined}")
+	}
+}